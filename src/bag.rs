@@ -1,6 +1,12 @@
 //! A bag that is both indexable and hash-able. The index order is sorted ord of all items in the bag.
 
-use std::{hash::Hash, iter::FromIterator, ops::Index};
+use std::{
+    hash::Hash,
+    iter::FromIterator,
+    ops::{Bound, Index, RangeBounds},
+};
+
+use crate::resolve_index_range;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, PartialOrd, Ord)]
 pub struct HIBag<T> {
@@ -64,6 +70,198 @@ impl<T> HIBag<T> {
 
         &self.items[index]
     }
+
+    /// Removes and returns the items in the given index range, leaving the remaining items in
+    /// their original sorted order.
+    pub fn drain<R>(&mut self, range: R) -> impl Iterator<Item = T> + '_
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lo, hi) = resolve_index_range(range, self.items.len());
+
+        self.items.drain(lo..hi)
+    }
+
+    /// Retains only the items for which `f` returns `true`, in place.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.items.retain(f);
+    }
+
+    /// Returns the first (smallest) item in the bag.
+    pub fn first(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Returns the last (largest) item in the bag.
+    pub fn last(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Returns the index of the first occurrence of the given item, if it is present in the bag.
+    pub fn index_of(&self, item: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let i = self.items.partition_point(|x| x < item);
+        if i < self.items.len() && &self.items[i] == item {
+            Some(i)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of times the given item occurs in the bag.
+    pub fn count(&self, item: &T) -> usize
+    where
+        T: Ord,
+    {
+        let lo = self.items.partition_point(|x| x < item);
+        let hi = self.items.partition_point(|x| x <= item);
+
+        hi - lo
+    }
+
+    /// Returns an iterator over the items that fall within `range`, in ascending order.
+    pub fn range<R>(&self, range: R) -> impl Iterator<Item = &T>
+    where
+        T: Ord,
+        R: RangeBounds<T>,
+    {
+        let lo = match range.start_bound() {
+            Bound::Included(start) => self.items.partition_point(|x| x < start),
+            Bound::Excluded(start) => self.items.partition_point(|x| x <= start),
+            Bound::Unbounded => 0,
+        };
+        let hi = match range.end_bound() {
+            Bound::Included(end) => self.items.partition_point(|x| x <= end),
+            Bound::Excluded(end) => self.items.partition_point(|x| x < end),
+            Bound::Unbounded => self.items.len(),
+        };
+
+        self.items[lo..hi].iter()
+    }
+
+    /// Returns a borrowed, indexable view over the whole bag.
+    pub fn as_slice(&self) -> HIBagSlice<'_, T> {
+        HIBagSlice { items: &self.items }
+    }
+
+    /// Returns a borrowed, indexable view over the given index range of the bag.
+    pub fn get_range<R>(&self, range: R) -> HIBagSlice<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lo, hi) = resolve_index_range(range, self.items.len());
+
+        HIBagSlice {
+            items: &self.items[lo..hi],
+        }
+    }
+}
+
+/// A borrowed, ordered, indexable view into a [`HIBag`].
+///
+/// Returned by [`HIBag::as_slice`] and [`HIBag::get_range`].
+#[derive(Debug)]
+pub struct HIBagSlice<'a, T> {
+    items: &'a [T],
+}
+
+impl<'a, T> HIBagSlice<'a, T> {
+    /// Returns the number of items in the slice.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true iff the slice contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns a reference to the item at the given index. Panics if the index is out of bounds.
+    pub fn get_index(&self, index: usize) -> &T {
+        assert!(index < self.items.len(), "Index out of bounds");
+
+        &self.items[index]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+
+    /// Binary searches the slice for the given item.
+    pub fn binary_search(&self, item: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.items.binary_search(item)
+    }
+
+    /// Returns the index of the partition point according to the given predicate, assuming the
+    /// slice is partitioned according to it.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.items.partition_point(pred)
+    }
+
+    /// Returns a further sub-slice of this slice for the given index range.
+    pub fn get_range<R>(&self, range: R) -> HIBagSlice<'a, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lo, hi) = resolve_index_range(range, self.items.len());
+
+        HIBagSlice {
+            items: &self.items[lo..hi],
+        }
+    }
+}
+
+impl<T> HIBag<T> {
+    /// Builds a `HIBag` directly from an iterator that is already sorted in ascending order,
+    /// skipping the sort step. The caller must ensure the input is sorted; violating that will
+    /// not panic, but will leave the bag's ordering invariant broken.
+    pub fn from_sorted_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        HIBag {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Ord> Extend<T> for HIBag<T> {
+    /// Merges `iter` into the bag in a single linear pass: the incoming items are sorted once,
+    /// then merged against the existing sorted items (keeping duplicates from both sides),
+    /// rather than performing an O(n) `insert` per incoming item.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut incoming: Vec<T> = iter.into_iter().collect();
+        incoming.sort();
+
+        let mut merged = Vec::with_capacity(self.items.len() + incoming.len());
+        let mut existing = std::mem::take(&mut self.items).into_iter().peekable();
+        let mut incoming = incoming.into_iter().peekable();
+
+        loop {
+            match (existing.peek(), incoming.peek()) {
+                (Some(x), Some(y)) => {
+                    if y < x {
+                        merged.push(incoming.next().unwrap());
+                    } else {
+                        merged.push(existing.next().unwrap());
+                    }
+                }
+                (Some(_), None) => merged.extend(existing.by_ref()),
+                (None, Some(_)) => merged.extend(incoming.by_ref()),
+                (None, None) => break,
+            }
+        }
+
+        self.items = merged;
+    }
 }
 
 impl<T> Index<usize> for HIBag<T> {
@@ -91,15 +289,61 @@ where
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut set = HIBag::new();
 
-        iter.into_iter().for_each(|t| {
-            set.insert(t);
-            ()
-        });
+        set.extend(iter);
 
         set
     }
 }
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+impl<T: Sync> HIBag<T> {
+    /// Returns a parallel iterator over the bag's items, backed by the sorted `Vec`.
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T> {
+        self.items.par_iter()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Send> IntoParallelIterator for HIBag<T> {
+    type Item = T;
+    type Iter = rayon::vec::IntoIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.items.into_par_iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for HIBag<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(&self.items)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for HIBag<T>
+where
+    T: serde::Deserialize<'de> + Ord,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let items = Vec::<T>::deserialize(deserializer)?;
+
+        Ok(items.into_iter().collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +407,156 @@ mod tests {
         assert_eq!(1, bag.len());
         assert_eq!(false, bag.contains(&1));
     }
+
+    #[test]
+    fn first_and_last_return_smallest_and_largest() {
+        let mut bag = HIBag::new();
+
+        bag.insert(3);
+        bag.insert(1);
+        bag.insert(2);
+
+        assert_eq!(Some(&1), bag.first());
+        assert_eq!(Some(&3), bag.last());
+    }
+
+    #[test]
+    fn index_of_finds_first_occurrence() {
+        let mut bag = HIBag::new();
+
+        bag.insert(1);
+        bag.insert(2);
+        bag.insert(2);
+        bag.insert(3);
+
+        assert_eq!(Some(1), bag.index_of(&2));
+        assert_eq!(None, bag.index_of(&4));
+    }
+
+    #[test]
+    fn count_returns_multiplicity() {
+        let mut bag = HIBag::new();
+
+        bag.insert(1);
+        bag.insert(2);
+        bag.insert(2);
+        bag.insert(3);
+
+        assert_eq!(2, bag.count(&2));
+        assert_eq!(1, bag.count(&1));
+        assert_eq!(0, bag.count(&4));
+    }
+
+    #[test]
+    fn range_returns_items_within_bounds() {
+        let mut bag = HIBag::new();
+
+        bag.insert(1);
+        bag.insert(2);
+        bag.insert(2);
+        bag.insert(3);
+        bag.insert(4);
+
+        let values: Vec<&i32> = bag.range(2..4).collect();
+
+        assert_eq!(vec![&2, &2, &3], values);
+    }
+
+    #[test]
+    fn drain_removes_and_returns_index_range() {
+        let mut bag = HIBag::new();
+
+        bag.insert(1);
+        bag.insert(2);
+        bag.insert(2);
+        bag.insert(3);
+        bag.insert(4);
+
+        let drained: Vec<i32> = bag.drain(1..3).collect();
+
+        assert_eq!(vec![2, 2], drained);
+        assert_eq!(vec![&1, &3, &4], bag.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn retain_keeps_matching_items_in_order() {
+        let mut bag = HIBag::new();
+
+        bag.insert(1);
+        bag.insert(2);
+        bag.insert(3);
+        bag.insert(4);
+
+        bag.retain(|x| x % 2 == 0);
+
+        assert_eq!(vec![&2, &4], bag.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn from_sorted_iter_skips_sorting() {
+        let bag = HIBag::from_sorted_iter(vec![1, 2, 2, 3]);
+
+        assert_eq!(4, bag.len());
+        assert_eq!(&2, bag.get_index(1));
+    }
+
+    #[test]
+    fn extend_merges_sorted_and_keeps_duplicates() {
+        let mut bag = HIBag::new();
+
+        bag.insert(1);
+        bag.insert(3);
+
+        bag.extend(vec![2, 3, 2]);
+
+        let items: Vec<&i32> = bag.iter().collect();
+
+        assert_eq!(vec![&1, &2, &2, &3, &3], items);
+    }
+
+    #[test]
+    fn from_iter_builds_sorted_bag_with_duplicates() {
+        let bag: HIBag<i32> = vec![3, 1, 2, 1].into_iter().collect();
+
+        let items: Vec<&i32> = bag.iter().collect();
+
+        assert_eq!(vec![&1, &1, &2, &3], items);
+    }
+
+    #[test]
+    fn as_slice_exposes_all_items_in_order() {
+        let mut bag = HIBag::new();
+
+        bag.insert(3);
+        bag.insert(1);
+        bag.insert(2);
+
+        let slice = bag.as_slice();
+
+        assert_eq!(3, slice.len());
+        assert_eq!(&1, slice.get_index(0));
+        assert_eq!(&2, slice.get_index(1));
+        assert_eq!(&3, slice.get_index(2));
+    }
+
+    #[test]
+    fn get_range_returns_sub_slice() {
+        let mut bag = HIBag::new();
+
+        bag.insert(1);
+        bag.insert(2);
+        bag.insert(2);
+        bag.insert(3);
+        bag.insert(4);
+
+        let slice = bag.get_range(1..4);
+
+        assert_eq!(3, slice.len());
+        assert_eq!(&2, slice.get_index(0));
+
+        let sub_slice = slice.get_range(1..);
+
+        assert_eq!(2, sub_slice.len());
+        assert_eq!(&2, sub_slice.get_index(0));
+    }
 }
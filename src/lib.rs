@@ -1,6 +1,28 @@
 pub mod bag;
 pub mod set;
 
+use std::ops::{Bound, RangeBounds};
+
+/// Resolves a `RangeBounds<usize>` against a backing length into a concrete `start..end` pair,
+/// the way `Vec::drain` and friends do internally.
+pub(crate) fn resolve_index_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(start <= end, "start index must not exceed end index");
+    assert!(end <= len, "end index out of bounds");
+
+    (start, end)
+}
+
 #[macro_export]
 macro_rules! hi_set {
     ($( $item:expr ),*) => {{
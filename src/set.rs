@@ -2,10 +2,13 @@
 
 use std::{
     hash::Hash,
-    iter::FromIterator,
-    ops::{Index, IndexMut},
+    iter::{FromIterator, Peekable},
+    ops::{BitAnd, BitOr, BitXor, Bound, Index, IndexMut, RangeBounds, Sub},
+    slice,
 };
 
+use crate::resolve_index_range;
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, PartialOrd, Ord)]
 pub struct HISet<T> {
     items: Vec<T>,
@@ -79,6 +82,398 @@ impl<T> HISet<T> {
     pub fn clear(&mut self) {
         self.items.clear();
     }
+
+    /// Removes and returns the items in the given index range, leaving the remaining items in
+    /// their original sorted order.
+    pub fn drain<R>(&mut self, range: R) -> impl Iterator<Item = T> + '_
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lo, hi) = resolve_index_range(range, self.items.len());
+
+        self.items.drain(lo..hi)
+    }
+
+    /// Retains only the items for which `f` returns `true`, in place.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.items.retain(f);
+    }
+
+    /// Returns the first (smallest) item in the set.
+    pub fn first(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Returns the last (largest) item in the set.
+    pub fn last(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Returns the index of the given item, if it is present in the set.
+    pub fn index_of(&self, item: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.items.binary_search(item).ok()
+    }
+
+    /// Returns an iterator over the items that fall within `range`, in ascending order.
+    pub fn range<R>(&self, range: R) -> impl Iterator<Item = &T>
+    where
+        T: Ord,
+        R: RangeBounds<T>,
+    {
+        let lo = match range.start_bound() {
+            Bound::Included(start) => self.items.partition_point(|x| x < start),
+            Bound::Excluded(start) => self.items.partition_point(|x| x <= start),
+            Bound::Unbounded => 0,
+        };
+        let hi = match range.end_bound() {
+            Bound::Included(end) => self.items.partition_point(|x| x <= end),
+            Bound::Excluded(end) => self.items.partition_point(|x| x < end),
+            Bound::Unbounded => self.items.len(),
+        };
+
+        self.items[lo..hi].iter()
+    }
+
+    /// Returns a borrowed, indexable view over the whole set.
+    pub fn as_slice(&self) -> HISetSlice<'_, T> {
+        HISetSlice { items: &self.items }
+    }
+
+    /// Returns a borrowed, indexable view over the given index range of the set.
+    pub fn get_range<R>(&self, range: R) -> HISetSlice<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lo, hi) = resolve_index_range(range, self.items.len());
+
+        HISetSlice {
+            items: &self.items[lo..hi],
+        }
+    }
+
+    /// Visits the values representing the union, i.e. all the values in `self` or `other`,
+    /// without duplicates, in ascending order.
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T>
+    where
+        T: Ord,
+    {
+        Union {
+            a: self.items.iter().peekable(),
+            b: other.items.iter().peekable(),
+        }
+    }
+
+    /// Visits the values representing the intersection, i.e. the values that are in both
+    /// `self` and `other`, in ascending order.
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, T>
+    where
+        T: Ord,
+    {
+        Intersection {
+            a: self.items.iter().peekable(),
+            b: other.items.iter().peekable(),
+        }
+    }
+
+    /// Visits the values representing the difference, i.e. the values that are in `self` but
+    /// not in `other`, in ascending order.
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T>
+    where
+        T: Ord,
+    {
+        Difference {
+            a: self.items.iter().peekable(),
+            b: other.items.iter().peekable(),
+        }
+    }
+
+    /// Visits the values representing the symmetric difference, i.e. the values that are in
+    /// `self` or `other` but not in both, in ascending order.
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, T>
+    where
+        T: Ord,
+    {
+        SymmetricDifference {
+            a: self.items.iter().peekable(),
+            b: other.items.iter().peekable(),
+        }
+    }
+}
+
+/// A lazy iterator producing elements in the union of `HISet`s.
+///
+/// This struct is created by the [`HISet::union`] method.
+pub struct Union<'a, T> {
+    a: Peekable<slice::Iter<'a, T>>,
+    b: Peekable<slice::Iter<'a, T>>,
+}
+
+impl<'a, T: Ord> Iterator for Union<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match (self.a.peek(), self.b.peek()) {
+            (Some(x), Some(y)) => match x.cmp(y) {
+                std::cmp::Ordering::Less => self.a.next(),
+                std::cmp::Ordering::Greater => self.b.next(),
+                std::cmp::Ordering::Equal => {
+                    self.b.next();
+                    self.a.next()
+                }
+            },
+            (Some(_), None) => self.a.next(),
+            (None, Some(_)) => self.b.next(),
+            (None, None) => None,
+        }
+    }
+}
+
+/// A lazy iterator producing elements in the intersection of `HISet`s.
+///
+/// This struct is created by the [`HISet::intersection`] method.
+pub struct Intersection<'a, T> {
+    a: Peekable<slice::Iter<'a, T>>,
+    b: Peekable<slice::Iter<'a, T>>,
+}
+
+impl<'a, T: Ord> Iterator for Intersection<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match (self.a.peek(), self.b.peek()) {
+                (Some(x), Some(y)) => match x.cmp(y) {
+                    std::cmp::Ordering::Less => {
+                        self.a.next();
+                    }
+                    std::cmp::Ordering::Greater => {
+                        self.b.next();
+                    }
+                    std::cmp::Ordering::Equal => {
+                        self.b.next();
+                        return self.a.next();
+                    }
+                },
+                _ => return None,
+            }
+        }
+    }
+}
+
+/// A lazy iterator producing elements in the difference of `HISet`s.
+///
+/// This struct is created by the [`HISet::difference`] method.
+pub struct Difference<'a, T> {
+    a: Peekable<slice::Iter<'a, T>>,
+    b: Peekable<slice::Iter<'a, T>>,
+}
+
+impl<'a, T: Ord> Iterator for Difference<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match (self.a.peek(), self.b.peek()) {
+                (Some(x), Some(y)) => match x.cmp(y) {
+                    std::cmp::Ordering::Less => return self.a.next(),
+                    std::cmp::Ordering::Greater => {
+                        self.b.next();
+                    }
+                    std::cmp::Ordering::Equal => {
+                        self.a.next();
+                        self.b.next();
+                    }
+                },
+                (Some(_), None) => return self.a.next(),
+                (None, _) => return None,
+            }
+        }
+    }
+}
+
+/// A lazy iterator producing elements in the symmetric difference of `HISet`s.
+///
+/// This struct is created by the [`HISet::symmetric_difference`] method.
+pub struct SymmetricDifference<'a, T> {
+    a: Peekable<slice::Iter<'a, T>>,
+    b: Peekable<slice::Iter<'a, T>>,
+}
+
+impl<'a, T: Ord> Iterator for SymmetricDifference<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match (self.a.peek(), self.b.peek()) {
+                (Some(x), Some(y)) => match x.cmp(y) {
+                    std::cmp::Ordering::Less => return self.a.next(),
+                    std::cmp::Ordering::Greater => return self.b.next(),
+                    std::cmp::Ordering::Equal => {
+                        self.a.next();
+                        self.b.next();
+                    }
+                },
+                (Some(_), None) => return self.a.next(),
+                (None, Some(_)) => return self.b.next(),
+                (None, None) => return None,
+            }
+        }
+    }
+}
+
+impl<T: Ord + Clone> BitOr<&HISet<T>> for &HISet<T> {
+    type Output = HISet<T>;
+
+    /// Returns the union of `self` and `rhs` as a new `HISet`.
+    fn bitor(self, rhs: &HISet<T>) -> HISet<T> {
+        HISet {
+            items: self.union(rhs).cloned().collect(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> BitAnd<&HISet<T>> for &HISet<T> {
+    type Output = HISet<T>;
+
+    /// Returns the intersection of `self` and `rhs` as a new `HISet`.
+    fn bitand(self, rhs: &HISet<T>) -> HISet<T> {
+        HISet {
+            items: self.intersection(rhs).cloned().collect(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> BitXor<&HISet<T>> for &HISet<T> {
+    type Output = HISet<T>;
+
+    /// Returns the symmetric difference of `self` and `rhs` as a new `HISet`.
+    fn bitxor(self, rhs: &HISet<T>) -> HISet<T> {
+        HISet {
+            items: self.symmetric_difference(rhs).cloned().collect(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> Sub<&HISet<T>> for &HISet<T> {
+    type Output = HISet<T>;
+
+    /// Returns the difference of `self` and `rhs` as a new `HISet`.
+    fn sub(self, rhs: &HISet<T>) -> HISet<T> {
+        HISet {
+            items: self.difference(rhs).cloned().collect(),
+        }
+    }
+}
+
+/// A borrowed, ordered, indexable view into a [`HISet`].
+///
+/// Returned by [`HISet::as_slice`] and [`HISet::get_range`].
+#[derive(Debug)]
+pub struct HISetSlice<'a, T> {
+    items: &'a [T],
+}
+
+impl<'a, T> HISetSlice<'a, T> {
+    /// Returns the number of items in the slice.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true iff the slice contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns a reference to the item at the given index. Panics if the index is out of bounds.
+    pub fn get_index(&self, index: usize) -> &T {
+        assert!(index < self.items.len(), "Index out of bounds");
+
+        &self.items[index]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+
+    /// Binary searches the slice for the given item.
+    pub fn binary_search(&self, item: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.items.binary_search(item)
+    }
+
+    /// Returns the index of the partition point according to the given predicate, assuming the
+    /// slice is partitioned according to it.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.items.partition_point(pred)
+    }
+
+    /// Returns a further sub-slice of this slice for the given index range.
+    pub fn get_range<R>(&self, range: R) -> HISetSlice<'a, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lo, hi) = resolve_index_range(range, self.items.len());
+
+        HISetSlice {
+            items: &self.items[lo..hi],
+        }
+    }
+}
+
+impl<T> HISet<T> {
+    /// Builds a `HISet` directly from an iterator that is already sorted in ascending order,
+    /// skipping the sort step. The caller must also ensure there are no duplicates; violating
+    /// either requirement will not panic, but will leave the set's sorted, duplicate-free
+    /// invariant broken.
+    pub fn from_sorted_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        HISet {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Ord> Extend<T> for HISet<T> {
+    /// Merges `iter` into the set in a single linear pass: the incoming items are sorted and
+    /// deduplicated once, then merged against the existing sorted items, rather than performing
+    /// an O(n) `insert` per incoming item.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut incoming: Vec<T> = iter.into_iter().collect();
+        incoming.sort();
+        incoming.dedup();
+
+        let mut merged = Vec::with_capacity(self.items.len() + incoming.len());
+        let mut existing = std::mem::take(&mut self.items).into_iter().peekable();
+        let mut incoming = incoming.into_iter().peekable();
+
+        loop {
+            match (existing.peek(), incoming.peek()) {
+                (Some(x), Some(y)) => match x.cmp(y) {
+                    std::cmp::Ordering::Less => merged.push(existing.next().unwrap()),
+                    std::cmp::Ordering::Greater => merged.push(incoming.next().unwrap()),
+                    std::cmp::Ordering::Equal => {
+                        merged.push(existing.next().unwrap());
+                        incoming.next();
+                    }
+                },
+                (Some(_), None) => merged.extend(existing.by_ref()),
+                (None, Some(_)) => merged.extend(incoming.by_ref()),
+                (None, None) => break,
+            }
+        }
+
+        self.items = merged;
+    }
 }
 
 impl<T> Index<usize> for HISet<T> {
@@ -112,10 +507,7 @@ where
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut set = HISet::new();
 
-        iter.into_iter().for_each(|t| {
-            set.insert(t);
-            ()
-        });
+        set.extend(iter);
 
         set
     }
@@ -132,6 +524,55 @@ macro_rules! hi_set {
     }};
 }
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+impl<T: Sync> HISet<T> {
+    /// Returns a parallel iterator over the set's items, backed by the sorted `Vec`.
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T> {
+        self.items.par_iter()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Send> IntoParallelIterator for HISet<T> {
+    type Item = T;
+    type Iter = rayon::vec::IntoIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.items.into_par_iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for HISet<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(&self.items)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for HISet<T>
+where
+    T: serde::Deserialize<'de> + Ord,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let items = Vec::<T>::deserialize(deserializer)?;
+
+        Ok(items.into_iter().collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +635,174 @@ mod tests {
         assert_eq!(1, bag.len());
         assert_eq!(false, bag.contains(&1));
     }
+
+    #[test]
+    fn first_and_last_return_smallest_and_largest() {
+        let set = hi_set![3, 1, 2];
+
+        assert_eq!(Some(&1), set.first());
+        assert_eq!(Some(&3), set.last());
+    }
+
+    #[test]
+    fn index_of_finds_item() {
+        let set = hi_set![1, 2, 3];
+
+        assert_eq!(Some(1), set.index_of(&2));
+        assert_eq!(None, set.index_of(&4));
+    }
+
+    #[test]
+    fn range_returns_items_within_bounds() {
+        let set = hi_set![1, 2, 3, 4, 5];
+
+        let values: Vec<&i32> = set.range(2..4).collect();
+
+        assert_eq!(vec![&2, &3], values);
+    }
+
+    #[test]
+    fn drain_removes_and_returns_index_range() {
+        let mut set = hi_set![1, 2, 3, 4, 5];
+
+        let drained: Vec<i32> = set.drain(1..3).collect();
+
+        assert_eq!(vec![2, 3], drained);
+        assert_eq!(vec![&1, &4, &5], set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn retain_keeps_matching_items_in_order() {
+        let mut set = hi_set![1, 2, 3, 4, 5];
+
+        set.retain(|x| x % 2 == 0);
+
+        assert_eq!(vec![&2, &4], set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn from_sorted_iter_skips_sorting() {
+        let set = HISet::from_sorted_iter(vec![1, 2, 3]);
+
+        assert_eq!(3, set.len());
+        assert_eq!(&2, set.get_index(1));
+    }
+
+    #[test]
+    fn extend_merges_sorted_and_dedupes() {
+        let mut set = hi_set![1, 3, 5];
+
+        set.extend(vec![4, 3, 2]);
+
+        let items: Vec<&i32> = set.iter().collect();
+
+        assert_eq!(vec![&1, &2, &3, &4, &5], items);
+    }
+
+    #[test]
+    fn from_iter_builds_sorted_deduped_set() {
+        let set: HISet<i32> = vec![3, 1, 2, 1].into_iter().collect();
+
+        let items: Vec<&i32> = set.iter().collect();
+
+        assert_eq!(vec![&1, &2, &3], items);
+    }
+
+    #[test]
+    fn as_slice_exposes_all_items_in_order() {
+        let set = hi_set![3, 1, 2];
+        let slice = set.as_slice();
+
+        assert_eq!(3, slice.len());
+        assert_eq!(&1, slice.get_index(0));
+        assert_eq!(&2, slice.get_index(1));
+        assert_eq!(&3, slice.get_index(2));
+        assert_eq!(Ok(1), slice.binary_search(&2));
+    }
+
+    #[test]
+    fn get_range_returns_sub_slice() {
+        let set = hi_set![1, 2, 3, 4, 5];
+        let slice = set.get_range(1..4);
+
+        assert_eq!(3, slice.len());
+        assert_eq!(&2, slice.get_index(0));
+
+        let sub_slice = slice.get_range(1..);
+
+        assert_eq!(2, sub_slice.len());
+        assert_eq!(&3, sub_slice.get_index(0));
+    }
+
+    #[test]
+    fn union_merges_without_duplicates() {
+        let a = hi_set![1, 2, 3];
+        let b = hi_set![2, 3, 4];
+
+        let union: Vec<&i32> = a.union(&b).collect();
+
+        assert_eq!(vec![&1, &2, &3, &4], union);
+    }
+
+    #[test]
+    fn intersection_keeps_only_shared_items() {
+        let a = hi_set![1, 2, 3];
+        let b = hi_set![2, 3, 4];
+
+        let intersection: Vec<&i32> = a.intersection(&b).collect();
+
+        assert_eq!(vec![&2, &3], intersection);
+    }
+
+    #[test]
+    fn difference_keeps_only_items_unique_to_self() {
+        let a = hi_set![1, 2, 3];
+        let b = hi_set![2, 3, 4];
+
+        let difference: Vec<&i32> = a.difference(&b).collect();
+
+        assert_eq!(vec![&1], difference);
+    }
+
+    #[test]
+    fn symmetric_difference_keeps_only_items_not_shared() {
+        let a = hi_set![1, 2, 3];
+        let b = hi_set![2, 3, 4];
+
+        let symmetric_difference: Vec<&i32> = a.symmetric_difference(&b).collect();
+
+        assert_eq!(vec![&1, &4], symmetric_difference);
+    }
+
+    #[test]
+    fn bitor_returns_union() {
+        let a = hi_set![1, 2, 3];
+        let b = hi_set![2, 3, 4];
+
+        assert_eq!(hi_set![1, 2, 3, 4], &a | &b);
+    }
+
+    #[test]
+    fn bitand_returns_intersection() {
+        let a = hi_set![1, 2, 3];
+        let b = hi_set![2, 3, 4];
+
+        assert_eq!(hi_set![2, 3], &a & &b);
+    }
+
+    #[test]
+    fn bitxor_returns_symmetric_difference() {
+        let a = hi_set![1, 2, 3];
+        let b = hi_set![2, 3, 4];
+
+        assert_eq!(hi_set![1, 4], &a ^ &b);
+    }
+
+    #[test]
+    fn sub_returns_difference() {
+        let a = hi_set![1, 2, 3];
+        let b = hi_set![2, 3, 4];
+
+        assert_eq!(hi_set![1], &a - &b);
+    }
 }